@@ -0,0 +1,44 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use polars_core::prelude::*;
+use polars_io::csv::write::CsvWriter;
+use polars_io::SerWriter;
+
+/// A wide frame of `n_cols` numeric columns, the case the fast path in
+/// `write_impl::write` targets: no column ever needs quoting.
+fn numeric_frame(n_rows: usize, n_cols: usize) -> DataFrame {
+    let columns: Vec<Series> = (0..n_cols)
+        .map(|i| {
+            Series::new(
+                &format!("c{i}"),
+                (0..n_rows as i64).map(|v| v + i as i64).collect::<Vec<_>>(),
+            )
+        })
+        .collect();
+    DataFrame::new(columns).unwrap()
+}
+
+fn bench_write_csv_numeric(c: &mut Criterion) {
+    let df = numeric_frame(50_000, 16);
+
+    c.bench_function("write_csv_numeric_fast_path", |b| {
+        b.iter(|| {
+            let mut buf = Vec::new();
+            let mut df = df.clone();
+            CsvWriter::new(&mut buf).finish(&mut df).unwrap();
+        })
+    });
+
+    c.bench_function("write_csv_numeric_quote_always", |b| {
+        b.iter(|| {
+            let mut buf = Vec::new();
+            let mut df = df.clone();
+            CsvWriter::new(&mut buf)
+                .with_quote_style(polars_io::csv::write::QuoteStyle::Always)
+                .finish(&mut df)
+                .unwrap();
+        })
+    });
+}
+
+criterion_group!(benches, bench_write_csv_numeric);
+criterion_main!(benches);
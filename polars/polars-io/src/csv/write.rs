@@ -1,4 +1,4 @@
-pub use super::write_impl::CsvWriterOptions;
+pub use super::write_impl::{CsvWriterOptions, QuoteStyle, Terminator};
 use super::*;
 
 /// Writes a DataFrame as a CSV to the specified `Write`. Construct a `CsvWriter` with [`CsvWriter::new(buffer)`].
@@ -12,14 +12,32 @@ use super::*;
 /// float_precision: None
 /// delimiter: b','
 /// quote: b'"'
+/// quote_style: QuoteStyle::Necessary
+/// line_terminator: Terminator::LF
 /// null: String::new()
 /// batch_size: 1024
+/// auto_time_precision: true
 /// ```
 ///
+/// With `time_format`/`datetime_format` left at `None`, time and datetime columns are
+/// formatted with as many fractional-second digits as their `TimeUnit` carries (see
+/// [`with_auto_time_precision`](CsvWriter::with_auto_time_precision)), rather than
+/// always padding out to nanoseconds.
+///
 /// Use the `with_` methods to overwrite these options.
 ///
 /// ## Note
 /// Don't use a `Buffered` writer, the `CsvWriter` internally already buffers writes.
+///
+/// ## On ragged records
+/// There is deliberately no `flexible`/`with_flexible` option permitting ragged records
+/// (rows with a different field count than the header), unlike the `csv` crate's
+/// `WriterBuilder::flexible`. [`write_impl::write`] is the only write path in this
+/// crate and it always emits exactly one field per `DataFrame` column for every row —
+/// polars `DataFrame`s are rectangular by construction, so a record's field count can
+/// never diverge from the header's here. Without a row-iterator or streaming write path
+/// where raggedness could actually originate, a `flexible` option would guard against a
+/// condition the writer can't produce; it's declined rather than shipped as dead code.
 #[must_use]
 pub struct CsvWriter<W: Write> {
     /// File or Stream handler
@@ -34,13 +52,10 @@ where
 {
     /// Create a new `CsvWriter` with the default [`CsvWriterOptions`]
     fn new(buffer: W) -> Self {
-        // 9f: all nanoseconds
-        let options = CsvWriterOptions {
-            time_format: Some("%T%.9f".to_string()),
-            ..Default::default()
-        };
-
-        CsvWriter { buffer, options }
+        CsvWriter {
+            buffer,
+            options: CsvWriterOptions::default(),
+        }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
@@ -105,6 +120,16 @@ where
         self
     }
 
+    /// Set whether datetime columns with no explicit `datetime_format` derive their
+    /// fractional-second precision from the column's `TimeUnit` (the default), or always
+    /// format with fixed nanosecond precision as in previous versions. Time columns are
+    /// stored in nanoseconds and use nine fractional digits either way. An explicit
+    /// `with_time_format`/`with_datetime_format` always takes priority over this setting.
+    pub fn with_auto_time_precision(mut self, auto_time_precision: bool) -> Self {
+        self.options.auto_time_precision = auto_time_precision;
+        self
+    }
+
     /// Set the CSV file's float precision
     pub fn with_float_precision(mut self, precision: Option<usize>) -> Self {
         if precision.is_some() {
@@ -119,6 +144,21 @@ where
         self
     }
 
+    /// Set when fields get quoted. Defaults to [`QuoteStyle::Necessary`], which only
+    /// quotes fields containing the delimiter, the quote char, or a record terminator.
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.options.quote_style = quote_style;
+        self
+    }
+
+    /// Set the byte sequence written after each record. Defaults to [`Terminator::LF`];
+    /// use [`Terminator::CRLF`] for strict RFC-4180 output, or [`Terminator::Byte`] for
+    /// a custom single-byte terminator.
+    pub fn with_line_terminator(mut self, terminator: Terminator) -> Self {
+        self.options.line_terminator = terminator;
+        self
+    }
+
     /// Set the CSV file's null value representation
     pub fn with_null_value(mut self, null_value: String) -> Self {
         self.options.null = null_value;
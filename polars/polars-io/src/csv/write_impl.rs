@@ -0,0 +1,618 @@
+use std::io::Write;
+
+use polars_core::prelude::*;
+use polars_core::export::chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone as _, Utc};
+use polars_core::export::chrono_tz::Tz;
+
+/// Controls when fields are quoted, mirroring the modes of the `csv` crate's
+/// `QuoteStyle`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// Quote every field unconditionally.
+    Always,
+    /// Quote only fields that contain the delimiter, the quote char, or a record
+    /// terminator.
+    #[default]
+    Necessary,
+    /// Never quote fields. Returns an error if a field would need quoting.
+    Never,
+    /// Quote every field belonging to a non-numeric column.
+    NonNumeric,
+}
+
+/// The byte sequence written after each record.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Terminator {
+    /// `\n`
+    #[default]
+    LF,
+    /// `\r\n`
+    CRLF,
+    /// A single arbitrary byte.
+    Byte(u8),
+}
+
+impl Terminator {
+    /// Appends this terminator's bytes to `buf`.
+    fn write_to(self, buf: &mut Vec<u8>) {
+        match self {
+            Terminator::LF => buf.push(b'\n'),
+            Terminator::CRLF => buf.extend_from_slice(b"\r\n"),
+            Terminator::Byte(b) => buf.push(b),
+        }
+    }
+
+    /// Whether `b` is a byte this terminator ever emits, used to decide if an
+    /// embedded occurrence of `b` in a field must be quoted away.
+    fn contains_byte(self, b: u8) -> bool {
+        match self {
+            Terminator::LF => b == b'\n',
+            Terminator::CRLF => b == b'\r' || b == b'\n',
+            Terminator::Byte(t) => b == t,
+        }
+    }
+}
+
+/// Options for the CSV writer, shared by the lower-level [`write`] and [`write_header`]
+/// functions and the higher-level [`CsvWriter`](super::CsvWriter).
+#[derive(Clone, Debug)]
+pub struct CsvWriterOptions {
+    pub header: bool,
+    pub delimiter: u8,
+    pub quote: u8,
+    pub quote_style: QuoteStyle,
+    pub line_terminator: Terminator,
+    pub null: String,
+    pub batch_size: usize,
+    pub date_format: Option<String>,
+    pub time_format: Option<String>,
+    pub datetime_format: Option<String>,
+    pub float_precision: Option<usize>,
+    /// When no explicit `time_format`/`datetime_format` is set, derive the number of
+    /// fractional-second digits from each temporal column's [`TimeUnit`] instead of
+    /// always formatting with nanosecond precision.
+    pub auto_time_precision: bool,
+}
+
+impl Default for CsvWriterOptions {
+    fn default() -> Self {
+        Self {
+            header: true,
+            delimiter: b',',
+            quote: b'"',
+            quote_style: QuoteStyle::default(),
+            line_terminator: Terminator::default(),
+            null: String::new(),
+            batch_size: 1024,
+            date_format: None,
+            time_format: None,
+            datetime_format: None,
+            float_precision: None,
+            auto_time_precision: true,
+        }
+    }
+}
+
+/// The `strftime`-style fractional-second suffix (if any) matching `tu`'s precision.
+fn time_unit_fraction_format(tu: TimeUnit) -> &'static str {
+    match tu {
+        TimeUnit::Milliseconds => "%.3f",
+        TimeUnit::Microseconds => "%.6f",
+        TimeUnit::Nanoseconds => "%.9f",
+    }
+}
+
+/// The date format to use for a `Date` column, honoring an explicit user format.
+fn resolve_date_format(options: &CsvWriterOptions) -> String {
+    options
+        .date_format
+        .clone()
+        .unwrap_or_else(|| "%Y-%m-%d".to_string())
+}
+
+/// The time format to use for a `Time` column, honoring an explicit user format.
+/// `Time` values are always stored with nanosecond precision, so there's nothing for
+/// `auto_time_precision` to derive here; it only affects `Datetime` columns.
+fn resolve_time_format(options: &CsvWriterOptions) -> String {
+    options
+        .time_format
+        .clone()
+        .unwrap_or_else(|| "%T%.9f".to_string())
+}
+
+/// The datetime format to use for a `Datetime` column of the given `TimeUnit`,
+/// honoring an explicit user format and otherwise deriving one from
+/// `auto_time_precision`.
+fn resolve_datetime_format(tu: TimeUnit, options: &CsvWriterOptions) -> String {
+    if let Some(fmt) = &options.datetime_format {
+        return fmt.clone();
+    }
+    let fraction = if options.auto_time_precision {
+        time_unit_fraction_format(tu)
+    } else {
+        "%.9f"
+    };
+    format!("%Y-%m-%dT%H:%M:%S{fraction}")
+}
+
+fn format_date_value(days_since_epoch: i32, fmt: &str) -> String {
+    match NaiveDate::from_ymd_opt(1970, 1, 1)
+        .and_then(|epoch| epoch.checked_add_signed(Duration::days(days_since_epoch as i64)))
+    {
+        Some(d) => d.format(fmt).to_string(),
+        None => String::new(),
+    }
+}
+
+fn format_time_value(ns_since_midnight: i64, fmt: &str) -> String {
+    let secs = ns_since_midnight.div_euclid(1_000_000_000);
+    let nanos = ns_since_midnight.rem_euclid(1_000_000_000) as u32;
+    match NaiveTime::from_num_seconds_from_midnight_opt(secs as u32, nanos) {
+        Some(t) => t.format(fmt).to_string(),
+        None => String::new(),
+    }
+}
+
+/// Formats a `Datetime` value stored as `ts` ticks of `tu` since the epoch. If `tz` names
+/// a valid IANA zone, `ts` is treated as a UTC instant and converted to that zone's local
+/// wall-clock time before formatting, matching how the column's values are displayed
+/// elsewhere; otherwise (no `tz`, or a `tz` we fail to parse) it's formatted as-is.
+fn format_datetime_value(ts: i64, tu: TimeUnit, tz: Option<&str>, fmt: &str) -> String {
+    let (secs, nanos) = match tu {
+        TimeUnit::Milliseconds => (ts.div_euclid(1_000), (ts.rem_euclid(1_000) * 1_000_000) as u32),
+        TimeUnit::Microseconds => (ts.div_euclid(1_000_000), (ts.rem_euclid(1_000_000) * 1_000) as u32),
+        TimeUnit::Nanoseconds => (ts.div_euclid(1_000_000_000), ts.rem_euclid(1_000_000_000) as u32),
+    };
+    let naive = match NaiveDateTime::from_timestamp_opt(secs, nanos) {
+        Some(dt) => dt,
+        None => return String::new(),
+    };
+    match tz.and_then(|tz| tz.parse::<Tz>().ok()) {
+        Some(tz) => Utc.from_utc_datetime(&naive).with_timezone(&tz).format(fmt).to_string(),
+        None => naive.format(fmt).to_string(),
+    }
+}
+
+/// Returns `true` if `field` contains a byte that forces quoting under the
+/// "quote only when necessary" heuristic: the delimiter, the quote char, or a
+/// record terminator.
+fn needs_quoting(field: &[u8], options: &CsvWriterOptions) -> bool {
+    field.iter().any(|&b| {
+        b == options.delimiter
+            || b == options.quote
+            || b == b'\n'
+            || b == b'\r'
+            || options.line_terminator.contains_byte(b)
+    })
+}
+
+/// Decides whether `field` (belonging to a column that is numeric iff `is_numeric_col`)
+/// should be quoted under `options.quote_style`, erroring for [`QuoteStyle::Never`] if
+/// quoting would be required to keep the output well-formed.
+fn should_quote(field: &[u8], is_numeric_col: bool, options: &CsvWriterOptions) -> PolarsResult<bool> {
+    match options.quote_style {
+        QuoteStyle::Always => Ok(true),
+        QuoteStyle::Necessary => Ok(needs_quoting(field, options)),
+        QuoteStyle::Never => {
+            polars_ensure!(
+                !needs_quoting(field, options),
+                ComputeError: "field requires quoting but QuoteStyle::Never is set: {:?}",
+                String::from_utf8_lossy(field)
+            );
+            Ok(false)
+        },
+        QuoteStyle::NonNumeric => Ok(!is_numeric_col),
+    }
+}
+
+/// Bytes that can appear in the textual representation of a numeric or boolean value
+/// (digits, sign, decimal point, exponent, and the letters of `true`/`false`/`inf`/
+/// `NaN`). Deliberately over-inclusive: the cost of wrongly falling back to the slow
+/// path is a missed optimization, the cost of wrongly skipping it is a corrupt file.
+const NUMERIC_SAFE_CHARSET: &[u8] = b"0123456789+-.eEtTrRuUfFaAlLsSiInN";
+
+/// Bytes that can appear in our own auto-derived date/time/datetime formats
+/// (`%Y-%m-%d`, `%Y-%m-%dT%H:%M:%S%.Nf`, and `%T%.Nf`): digits, the format's literal
+/// separators, and the sign `%Y` prints for years outside 0..=9999.
+const TEMPORAL_SAFE_CHARSET: &[u8] = b"0123456789+-:.T";
+
+/// Whether none of `options`'s delimiter, quote char, or line terminator bytes can ever
+/// appear in a value drawn from `charset`.
+fn charset_is_quote_safe(charset: &[u8], options: &CsvWriterOptions) -> bool {
+    if charset.contains(&options.delimiter) || charset.contains(&options.quote) {
+        return false;
+    }
+    match options.line_terminator {
+        Terminator::Byte(b) => !charset.contains(&b),
+        Terminator::LF | Terminator::CRLF => true,
+    }
+}
+
+/// Whether a column of dtype `dtype` can *never* require quoting, so the fast path in
+/// [`write`] may write its values straight into the buffer with no per-field quote
+/// scan. Conservative: anything not provably safe (strings, binary, categoricals, a
+/// user-supplied temporal format we don't control the charset of, [`QuoteStyle::Always`]
+/// which quotes unconditionally, or [`QuoteStyle::NonNumeric`] on a non-numeric column,
+/// which also always quotes) falls back to the slow, scanning path.
+fn column_is_quote_safe(dtype: &DataType, options: &CsvWriterOptions) -> bool {
+    if options.quote_style == QuoteStyle::Always {
+        return false;
+    }
+    if options.quote_style == QuoteStyle::NonNumeric && !dtype.is_numeric() {
+        return false;
+    }
+    match dtype {
+        DataType::Boolean => charset_is_quote_safe(NUMERIC_SAFE_CHARSET, options),
+        dt if dt.is_numeric() => charset_is_quote_safe(NUMERIC_SAFE_CHARSET, options),
+        DataType::Datetime(_, _) if options.datetime_format.is_none() => {
+            charset_is_quote_safe(TEMPORAL_SAFE_CHARSET, options)
+        },
+        DataType::Time if options.time_format.is_none() => {
+            charset_is_quote_safe(TEMPORAL_SAFE_CHARSET, options)
+        },
+        DataType::Date if options.date_format.is_none() => {
+            charset_is_quote_safe(TEMPORAL_SAFE_CHARSET, options)
+        },
+        _ => false,
+    }
+}
+
+/// The formatted byte width (before any quoting) of a single value from a column of
+/// dtype `dtype`, used to pre-size the write buffer. An upper bound for booleans,
+/// integers, floats and the auto-derived temporal formats; an estimate for user-supplied
+/// temporal formats and variable-width types, where the buffer grows if it is exceeded.
+fn column_max_width(dtype: &DataType, format: Option<&str>, options: &CsvWriterOptions) -> usize {
+    match dtype {
+        DataType::Boolean => 5, // "false"
+        dt if dt.is_integer() => 20, // i64/u64::MIN/MAX with sign
+        DataType::Float32 | DataType::Float64 => match options.float_precision {
+            // sign + up to 309 integer digits (f64::MAX) + '.' + precision
+            Some(p) => p + 311,
+            // Rust's default `{}` never uses exponent notation, so the largest and
+            // smallest-magnitude finite f64 values expand to full decimal: measured
+            // worst case is 327 bytes (smallest negative subnormal); 350 leaves margin.
+            None => 350,
+        },
+        DataType::Datetime(_, _) | DataType::Time | DataType::Date => format.map_or(32, |f| f.len() + 16),
+        _ => 16, // heuristic for variable-width types; the buffer grows if exceeded
+    }
+}
+
+fn write_escaped_field(
+    buf: &mut Vec<u8>,
+    field: &[u8],
+    is_numeric_col: bool,
+    options: &CsvWriterOptions,
+) -> PolarsResult<()> {
+    if should_quote(field, is_numeric_col, options)? {
+        buf.push(options.quote);
+        for &b in field {
+            if b == options.quote {
+                buf.push(options.quote);
+            }
+            buf.push(b);
+        }
+        buf.push(options.quote);
+    } else {
+        buf.extend_from_slice(field);
+    }
+    Ok(())
+}
+
+pub fn write_header<W: Write>(
+    writer: &mut W,
+    names: &[&str],
+    options: &CsvWriterOptions,
+) -> PolarsResult<()> {
+    let mut buf = Vec::with_capacity(names.iter().map(|n| n.len() + 1).sum());
+    for (i, name) in names.iter().enumerate() {
+        if i > 0 {
+            buf.push(options.delimiter);
+        }
+        // Headers are names, never numeric data, so `QuoteStyle::NonNumeric` quotes them.
+        write_escaped_field(&mut buf, name.as_bytes(), false, options)?;
+    }
+    options.line_terminator.write_to(&mut buf);
+    writer.write_all(&buf)?;
+    Ok(())
+}
+
+/// Writes an already-formatted `field` either straight into `buf` (the fast path, for
+/// columns [`column_is_quote_safe`] cleared) or through [`write_escaped_field`]'s
+/// per-byte quote scan. Must produce byte-identical output to the scanning path for
+/// any field a quote-safe column can actually produce.
+fn write_field(
+    buf: &mut Vec<u8>,
+    field: &[u8],
+    quote_safe: bool,
+    is_numeric_col: bool,
+    options: &CsvWriterOptions,
+) -> PolarsResult<()> {
+    if quote_safe {
+        buf.extend_from_slice(field);
+        Ok(())
+    } else {
+        write_escaped_field(buf, field, is_numeric_col, options)
+    }
+}
+
+fn write_anyvalue(
+    buf: &mut Vec<u8>,
+    value: AnyValue,
+    is_numeric_col: bool,
+    quote_safe: bool,
+    temporal_format: Option<&str>,
+    options: &CsvWriterOptions,
+) -> PolarsResult<()> {
+    match value {
+        AnyValue::Null => write_escaped_field(buf, options.null.as_bytes(), is_numeric_col, options)?,
+        AnyValue::String(s) => write_field(buf, s.as_bytes(), quote_safe, is_numeric_col, options)?,
+        AnyValue::Boolean(v) => {
+            let s: &[u8] = if v { b"true" } else { b"false" };
+            write_field(buf, s, quote_safe, is_numeric_col, options)?;
+        },
+        AnyValue::Date(days) => {
+            let s = format_date_value(days, temporal_format.expect("date column must carry a format"));
+            write_field(buf, s.as_bytes(), quote_safe, is_numeric_col, options)?;
+        },
+        AnyValue::Time(ns) => {
+            let s = format_time_value(ns, temporal_format.expect("time column must carry a format"));
+            write_field(buf, s.as_bytes(), quote_safe, is_numeric_col, options)?;
+        },
+        AnyValue::Datetime(ts, tu, tz) => {
+            let s = format_datetime_value(
+                ts,
+                tu,
+                tz.as_ref().map(|tz| tz.as_str()),
+                temporal_format.expect("datetime column must carry a format"),
+            );
+            write_field(buf, s.as_bytes(), quote_safe, is_numeric_col, options)?;
+        },
+        AnyValue::Int8(v) => write_numeric(buf, v, quote_safe, is_numeric_col, options)?,
+        AnyValue::Int16(v) => write_numeric(buf, v, quote_safe, is_numeric_col, options)?,
+        AnyValue::Int32(v) => write_numeric(buf, v, quote_safe, is_numeric_col, options)?,
+        AnyValue::Int64(v) => write_numeric(buf, v, quote_safe, is_numeric_col, options)?,
+        AnyValue::UInt8(v) => write_numeric(buf, v, quote_safe, is_numeric_col, options)?,
+        AnyValue::UInt16(v) => write_numeric(buf, v, quote_safe, is_numeric_col, options)?,
+        AnyValue::UInt32(v) => write_numeric(buf, v, quote_safe, is_numeric_col, options)?,
+        AnyValue::UInt64(v) => write_numeric(buf, v, quote_safe, is_numeric_col, options)?,
+        AnyValue::Float32(v) => write_float(buf, v, quote_safe, is_numeric_col, options)?,
+        AnyValue::Float64(v) => write_float(buf, v, quote_safe, is_numeric_col, options)?,
+        _ => {
+            // Fallback: defer to the value's own Display impl and run it through the
+            // same escaping path as strings, since we can't assume it's quote-safe.
+            let s = format!("{value}");
+            write_escaped_field(buf, s.as_bytes(), is_numeric_col, options)?;
+        },
+    }
+    Ok(())
+}
+
+/// Writes an integer directly into `buf` with no intermediate allocation when
+/// `quote_safe`; otherwise formats into a scratch buffer first so it can be run
+/// through the quote-scanning path.
+fn write_numeric<T: std::fmt::Display>(
+    buf: &mut Vec<u8>,
+    v: T,
+    quote_safe: bool,
+    is_numeric_col: bool,
+    options: &CsvWriterOptions,
+) -> PolarsResult<()> {
+    if quote_safe {
+        write!(buf, "{v}").unwrap();
+        Ok(())
+    } else {
+        let s = v.to_string();
+        write_escaped_field(buf, s.as_bytes(), is_numeric_col, options)
+    }
+}
+
+fn write_float<T: std::fmt::Display>(
+    buf: &mut Vec<u8>,
+    v: T,
+    quote_safe: bool,
+    is_numeric_col: bool,
+    options: &CsvWriterOptions,
+) -> PolarsResult<()> {
+    if quote_safe {
+        match options.float_precision {
+            Some(p) => write!(buf, "{v:.p$}").unwrap(),
+            None => write!(buf, "{v}").unwrap(),
+        }
+        Ok(())
+    } else {
+        let s = match options.float_precision {
+            Some(p) => format!("{v:.p$}"),
+            None => format!("{v}"),
+        };
+        write_escaped_field(buf, s.as_bytes(), is_numeric_col, options)
+    }
+}
+
+/// Per-column format string for temporal columns (`Datetime`/`Time`/`Date`), computed
+/// once up front (for `Datetime`, from the column's `TimeUnit`) so the hot per-value loop
+/// doesn't redo it.
+fn column_temporal_formats(columns: &[Series], options: &CsvWriterOptions) -> Vec<Option<String>> {
+    columns
+        .iter()
+        .map(|s| match s.dtype() {
+            DataType::Datetime(tu, _) => Some(resolve_datetime_format(*tu, options)),
+            DataType::Time => Some(resolve_time_format(options)),
+            DataType::Date => Some(resolve_date_format(options)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The number of bytes [`Terminator`] appends to a record.
+fn terminator_width(terminator: Terminator) -> usize {
+    match terminator {
+        Terminator::LF | Terminator::Byte(_) => 1,
+        Terminator::CRLF => 2,
+    }
+}
+
+pub fn write<W: Write>(writer: &mut W, df: &DataFrame, options: &CsvWriterOptions) -> PolarsResult<()> {
+    let n_rows = df.height();
+    let columns = df.get_columns();
+    let is_numeric_col: Vec<bool> = columns.iter().map(|s| s.dtype().is_numeric()).collect();
+    let temporal_formats = column_temporal_formats(columns, options);
+    // Fast path: columns whose dtype can never require quoting skip the per-field
+    // quote scan entirely and get written straight into the buffer.
+    let quote_safe: Vec<bool> = columns
+        .iter()
+        .map(|s| column_is_quote_safe(s.dtype(), options))
+        .collect();
+
+    let row_upper_bound: usize = columns
+        .iter()
+        .zip(&temporal_formats)
+        .map(|(s, fmt)| column_max_width(s.dtype(), fmt.as_deref(), options))
+        .sum::<usize>()
+        + columns.len().saturating_sub(1) // delimiters
+        + terminator_width(options.line_terminator);
+    let mut buf = Vec::with_capacity(row_upper_bound * options.batch_size.min(n_rows.max(1)));
+
+    let mut row_idx = 0;
+    while row_idx < n_rows {
+        let end = (row_idx + options.batch_size).min(n_rows);
+        buf.clear();
+        buf.reserve(row_upper_bound * (end - row_idx));
+        for row in row_idx..end {
+            for (col_idx, s) in columns.iter().enumerate() {
+                if col_idx > 0 {
+                    buf.push(options.delimiter);
+                }
+                let av = s.get(row)?;
+                write_anyvalue(
+                    &mut buf,
+                    av,
+                    is_numeric_col[col_idx],
+                    quote_safe[col_idx],
+                    temporal_formats[col_idx].as_deref(),
+                    options,
+                )?;
+            }
+            options.line_terminator.write_to(&mut buf);
+        }
+        writer.write_all(&buf)?;
+        row_idx = end;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_to_string(df: &DataFrame, options: &CsvWriterOptions) -> String {
+        let mut buf = Vec::new();
+        write(&mut buf, df, options).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn quote_style_non_numeric_quotes_non_numeric_columns_only() {
+        let df = DataFrame::new(vec![
+            Series::new("b", &[true, false]),
+            Series::new("n", &[1i32, 2]),
+        ])
+        .unwrap();
+        let options = CsvWriterOptions {
+            quote_style: QuoteStyle::NonNumeric,
+            ..Default::default()
+        };
+        assert_eq!(write_to_string(&df, &options), "\"true\",1\n\"false\",2\n");
+    }
+
+    #[test]
+    fn quote_style_always_quotes_every_field() {
+        let df = DataFrame::new(vec![Series::new("n", &[1i32, 2])]).unwrap();
+        let options = CsvWriterOptions {
+            quote_style: QuoteStyle::Always,
+            ..Default::default()
+        };
+        assert_eq!(write_to_string(&df, &options), "\"1\"\n\"2\"\n");
+    }
+
+    #[test]
+    fn quote_style_never_errors_when_quoting_is_required() {
+        let df = DataFrame::new(vec![Series::new("s", &["a,b"])]).unwrap();
+        let options = CsvWriterOptions {
+            quote_style: QuoteStyle::Never,
+            ..Default::default()
+        };
+        assert!(write(&mut Vec::new(), &df, &options).is_err());
+    }
+
+    #[test]
+    fn line_terminator_crlf_is_used_instead_of_lf() {
+        let df = DataFrame::new(vec![Series::new("n", &[1i32])]).unwrap();
+        let options = CsvWriterOptions {
+            line_terminator: Terminator::CRLF,
+            ..Default::default()
+        };
+        assert_eq!(write_to_string(&df, &options), "1\r\n");
+    }
+
+    #[test]
+    fn auto_time_precision_derives_fraction_digits_from_time_unit() {
+        let s = Int64Chunked::from_slice("dt", &[0])
+            .into_datetime(TimeUnit::Milliseconds, None)
+            .into_series();
+        let df = DataFrame::new(vec![s]).unwrap();
+        assert_eq!(
+            write_to_string(&df, &CsvWriterOptions::default()),
+            "1970-01-01T00:00:00.000\n"
+        );
+    }
+
+    #[test]
+    fn datetime_with_timezone_is_rendered_in_local_wall_clock_time() {
+        let s = Int64Chunked::from_slice("dt", &[0])
+            .into_datetime(TimeUnit::Milliseconds, Some("America/New_York".to_string()))
+            .into_series();
+        let df = DataFrame::new(vec![s]).unwrap();
+        assert_eq!(
+            write_to_string(&df, &CsvWriterOptions::default()),
+            "1969-12-31T19:00:00.000\n"
+        );
+    }
+
+    #[test]
+    fn fast_path_matches_slow_path_byte_for_byte() {
+        fn assert_parity(fast_df: DataFrame, slow_df: DataFrame) {
+            let options = CsvWriterOptions::default();
+            assert_eq!(write_to_string(&fast_df, &options), write_to_string(&slow_df, &options));
+        }
+
+        // Integers: the fast path writes numerics straight into the buffer; strings are
+        // never quote-safe, so comparing against the same text run through the scanning
+        // slow path checks write_numeric's fast branch against write_escaped_field.
+        assert_parity(
+            DataFrame::new(vec![Series::new("n", &[12i64, -34, 560])]).unwrap(),
+            DataFrame::new(vec![Series::new("n", &["12", "-34", "560"])]).unwrap(),
+        );
+
+        // Floats: same comparison, for write_float's fast branch.
+        assert_parity(
+            DataFrame::new(vec![Series::new("f", &[1.5f64, -0.0, 3.0])]).unwrap(),
+            DataFrame::new(vec![Series::new("f", &["1.5", "-0", "3"])]).unwrap(),
+        );
+
+        // Datetimes: quote-safe whenever `datetime_format` is unset, since our own
+        // auto-derived format can never contain the delimiter/quote/terminator.
+        let dt = Int64Chunked::from_slice("dt", &[0, 1_000])
+            .into_datetime(TimeUnit::Milliseconds, None)
+            .into_series();
+        assert_parity(
+            DataFrame::new(vec![dt]).unwrap(),
+            DataFrame::new(vec![Series::new(
+                "dt",
+                &["1970-01-01T00:00:00.000", "1970-01-01T00:00:01.000"],
+            )])
+            .unwrap(),
+        );
+    }
+}